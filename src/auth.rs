@@ -0,0 +1,121 @@
+use actix_web::dev::Payload;
+use actix_web::http::header;
+use actix_web::{post, web, FromRequest, HttpRequest, HttpResponse};
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::Argon2;
+use chrono::{Duration, Utc};
+use futures_util::future::{ready, Ready};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{ok, AppError};
+use crate::model::{LoginRequest, LoginResponse, UserModel};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: i32,
+    pub exp: usize,
+}
+
+/// Signs a JWT for `user_id` that expires `expiry_seconds` from now.
+pub fn create_token(
+    user_id: i32,
+    secret: &str,
+    expiry_seconds: i64,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    let exp = (Utc::now() + Duration::seconds(expiry_seconds)).timestamp() as usize;
+    let claims = Claims { sub: user_id, exp };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+/// Route guard extractor: parses and validates the `Authorization: Bearer` header.
+pub struct AuthUser {
+    pub user_id: i32,
+}
+
+impl FromRequest for AuthUser {
+    type Error = AppError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let Some(data) = req.app_data::<web::Data<AppState>>() else {
+            return ready(Err(AppError::Internal("Missing app state".to_string())));
+        };
+
+        let token = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        let Some(token) = token else {
+            return ready(Err(AppError::Unauthorized(
+                "Missing bearer token".to_string(),
+            )));
+        };
+
+        let decoded = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(data.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        );
+
+        match decoded {
+            Ok(token_data) => ready(Ok(AuthUser {
+                user_id: token_data.claims.sub,
+            })),
+            Err(_) => ready(Err(AppError::Unauthorized(
+                "Invalid or expired token".to_string(),
+            ))),
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Login successful", body = LoginResponse),
+        (status = 401, description = "Invalid credentials", body = crate::errors::ErrorBody),
+    )
+)]
+#[post("/auth/login")]
+async fn login(
+    data: web::Data<AppState>,
+    request: web::Json<LoginRequest>,
+) -> Result<HttpResponse, AppError> {
+    let user = sqlx::query_as!(
+        UserModel,
+        r#"SELECT id, username, password_hash FROM public.users WHERE username = $1"#,
+        request.username,
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| AppError::Unauthorized("Invalid credentials".to_string()))?;
+
+    let parsed_hash = PasswordHash::new(&user.password_hash)
+        .map_err(|_| AppError::Internal("Corrupt password hash".to_string()))?;
+
+    if Argon2::default()
+        .verify_password(request.password.as_bytes(), &parsed_hash)
+        .is_err()
+    {
+        return Err(AppError::Unauthorized("Invalid credentials".to_string()));
+    }
+
+    let token = create_token(user.id, &data.config.jwt_secret, data.config.jwt_expiry_seconds)
+        .map_err(|_| AppError::Internal("Failed to create token".to_string()))?;
+
+    Ok(ok(LoginResponse { token }))
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(login);
+}
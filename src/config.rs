@@ -0,0 +1,58 @@
+use std::env;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Typed startup configuration, loaded once from the environment.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub http_host: String,
+    pub http_port: u16,
+    pub jwt_secret: String,
+    pub jwt_expiry_seconds: i64,
+    pub max_db_connections: u32,
+    pub log_format: String,
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("missing environment variable {0}")]
+    Missing(&'static str),
+    #[error("invalid value for environment variable {0}: {1:?}")]
+    Invalid(&'static str, String),
+}
+
+impl Config {
+    /// Loads and validates all environment variables in one place.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Ok(Self {
+            database_url: require_var("DATABASE_URL")?,
+            http_host: optional_var("HTTP_HOST", "127.0.0.1".to_string()),
+            http_port: parse_var("HTTP_PORT", require_var("HTTP_PORT")?)?,
+            jwt_secret: require_var("JWT_SECRET")?,
+            jwt_expiry_seconds: match env::var("JWT_EXPIRY_SECONDS") {
+                Ok(raw) => parse_var("JWT_EXPIRY_SECONDS", raw)?,
+                Err(_) => 3600,
+            },
+            max_db_connections: match env::var("MAX_DB_CONNECTIONS") {
+                Ok(raw) => parse_var("MAX_DB_CONNECTIONS", raw)?,
+                Err(_) => 10,
+            },
+            log_format: optional_var("LOG_FORMAT", "info".to_string()),
+        })
+    }
+}
+
+fn require_var(key: &'static str) -> Result<String, ConfigError> {
+    env::var(key).map_err(|_| ConfigError::Missing(key))
+}
+
+fn optional_var(key: &'static str, default: String) -> String {
+    env::var(key).unwrap_or(default)
+}
+
+fn parse_var<T: FromStr>(key: &'static str, raw: String) -> Result<T, ConfigError> {
+    raw.parse::<T>()
+        .map_err(|_| ConfigError::Invalid(key, raw))
+}
@@ -0,0 +1,94 @@
+use actix_web::error::{JsonPayloadError, QueryPayloadError};
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use serde::Serialize;
+use thiserror::Error;
+
+/// Application-wide error type. Every variant renders as a consistent
+/// `{"status":"error","message":...}` JSON body via `ResponseError`.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error("database error: {0}")]
+    Database(sqlx::Error),
+    #[error("validation failed")]
+    Validation(validator::ValidationErrors),
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(db_err) = &err {
+            if db_err.code().as_deref() == Some("23503") {
+                return AppError::BadRequest("Invalid reference to a related resource".to_string());
+            }
+        }
+        AppError::Database(err)
+    }
+}
+
+impl From<validator::ValidationErrors> for AppError {
+    fn from(err: validator::ValidationErrors) -> Self {
+        AppError::Validation(err)
+    }
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ErrorBody {
+    status: &'static str,
+    message: String,
+}
+
+impl ResponseError for AppError {
+    fn error_response(&self) -> HttpResponse {
+        let body = ErrorBody {
+            status: "error",
+            message: self.to_string(),
+        };
+
+        match self {
+            AppError::NotFound(_) => HttpResponse::NotFound().json(body),
+            AppError::BadRequest(_) => HttpResponse::BadRequest().json(body),
+            AppError::Unauthorized(_) => HttpResponse::Unauthorized().json(body),
+            AppError::Validation(errors) => HttpResponse::BadRequest().json(serde_json::json!({
+                "status": "error",
+                "message": "Validation failed",
+                "errors": errors.field_errors(),
+            })),
+            AppError::Database(_) | AppError::Internal(_) => {
+                HttpResponse::InternalServerError().json(body)
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SuccessBody<T: Serialize> {
+    status: &'static str,
+    data: T,
+}
+
+/// Wraps a successful payload in the `{"status":"success","data":...}` envelope.
+pub fn ok<T: Serialize>(data: T) -> HttpResponse {
+    HttpResponse::Ok().json(SuccessBody {
+        status: "success",
+        data,
+    })
+}
+
+/// `web::JsonConfig` error handler so malformed JSON bodies render through
+/// `AppError` instead of actix-web's default plain-text 400.
+pub fn json_error_handler(err: JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    AppError::BadRequest(err.to_string()).into()
+}
+
+/// `web::QueryConfig` error handler so malformed query strings render through
+/// `AppError` instead of actix-web's default plain-text 400.
+pub fn query_error_handler(err: QueryPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    AppError::BadRequest(err.to_string()).into()
+}
@@ -1,66 +1,150 @@
+mod auth;
+mod config;
+mod errors;
+mod maintenance;
 mod model;
+mod openapi;
 mod schema;
 
 use actix_cors::Cors;
 use actix_web::middleware::Logger;
 use actix_web::{delete, get, post, HttpResponse, Responder};
 use actix_web::{web, App, HttpServer};
+use chrono::NaiveDateTime;
 use dotenv::dotenv;
 use env_logger;
 use sqlx::postgres::{PgPool, PgPoolOptions};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+use validator::Validate;
 
+use crate::auth::AuthUser;
+use crate::config::Config;
+use crate::errors::{ok, AppError, ErrorBody};
 use crate::model::*;
+use crate::openapi::ApiDoc;
 
 #[get("/")]
 async fn index() -> impl Responder {
     "Hello, World!"
 }
 
+const VEHICLES_DEFAULT_LIMIT: i64 = 20;
+const VEHICLES_MAX_LIMIT: i64 = 100;
+const VEHICLE_SORT_COLUMNS: &[&str] = &["id", "name"];
+
+#[utoipa::path(
+    get,
+    path = "/vehicles",
+    params(
+        ("limit" = Option<i64>, Query, description = "Max rows to return (default 20, capped at 100)"),
+        ("offset" = Option<i64>, Query, description = "Rows to skip"),
+        ("sort" = Option<String>, Query, description = "Sort column (id or name), prefix with - for descending"),
+        ("q" = Option<String>, Query, description = "Case-insensitive substring match on name/description"),
+    ),
+    responses((status = 200, description = "Paginated list of vehicles", body = [VehicleModel]))
+)]
 #[get("/vehicles")]
-async fn get_vehicles(data: web::Data<AppState>) -> impl Responder {
-    let result = sqlx::query_as!(
-        VehicleModel,
-        r#"SELECT id, "name", description FROM public.vehicles;"#,
+async fn get_vehicles(
+    data: web::Data<AppState>,
+    query: web::Query<GetVehiclesQuery>,
+) -> Result<HttpResponse, AppError> {
+    let limit = query.limit.unwrap_or(VEHICLES_DEFAULT_LIMIT).clamp(1, VEHICLES_MAX_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let (sort_column, descending) = match query.sort.as_deref() {
+        Some(sort) => match sort.strip_prefix('-') {
+            Some(column) => (column, true),
+            None => (sort, false),
+        },
+        None => ("id", false),
+    };
+    let sort_column = if VEHICLE_SORT_COLUMNS.contains(&sort_column) {
+        sort_column
+    } else {
+        "id"
+    };
+    let direction = if descending { "DESC" } else { "ASC" };
+
+    let pattern = query.q.as_deref().map(|q| format!("%{}%", q));
+
+    let sql = format!(
+        r#"SELECT id, "name", description FROM public.vehicles
+           WHERE ($1::text IS NULL OR "name" ILIKE $1 OR description ILIKE $1)
+           ORDER BY "{sort_column}" {direction}
+           LIMIT $2 OFFSET $3"#,
+    );
+
+    let vehicles: Vec<VehicleModel> = sqlx::query_as(&sql)
+        .bind(&pattern)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&data.db)
+        .await?;
+
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM public.vehicles WHERE ($1::text IS NULL OR "name" ILIKE $1 OR description ILIKE $1)"#,
+        pattern,
     )
-    .fetch_all(&data.db)
-    .await;
-
-    match result {
-        Ok(vehicles) => {
-            let json_response = serde_json::json!({
-                "rows": vehicles.len(),
-                "vehicles": vehicles
-            });
-            HttpResponse::Ok().json(json_response)
-        }
-        Err(_) => HttpResponse::InternalServerError().body("Failed to query vehicles"),
-    }
+    .fetch_one(&data.db)
+    .await?;
+
+    Ok(ok(serde_json::json!({
+        "rows": vehicles.len(),
+        "total": total,
+        "limit": limit,
+        "offset": offset,
+        "vehicles": vehicles
+    })))
 }
 
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}",
+    params(("id" = i32, Path, description = "Vehicle id")),
+    responses(
+        (status = 200, description = "Vehicle found", body = VehicleModel),
+        (status = 404, description = "Vehicle not found", body = ErrorBody),
+    )
+)]
 #[get("/vehicles/{id}")]
-async fn get_vehicle_by_id(data: web::Data<AppState>, path: web::Path<(i32,)>) -> impl Responder {
+async fn get_vehicle_by_id(
+    data: web::Data<AppState>,
+    path: web::Path<(i32,)>,
+) -> Result<HttpResponse, AppError> {
     let vehicle_id = path.into_inner().0;
-    let result = sqlx::query_as!(
+    let vehicle = sqlx::query_as!(
         VehicleModel,
         r#"SELECT id, "name", description FROM public.vehicles WHERE id = $1"#,
         vehicle_id,
     )
     .fetch_optional(&data.db)
-    .await;
+    .await?
+    .ok_or_else(|| AppError::NotFound("Vehicle not found".to_string()))?;
 
-    match result {
-        Ok(Some(vehicle)) => HttpResponse::Ok().json(vehicle),
-        Ok(None) => HttpResponse::NotFound().body("Vehicle not found"),
-        Err(_) => HttpResponse::InternalServerError().body("Failed to query vehicle"),
-    }
+    Ok(ok(vehicle))
 }
 
+#[utoipa::path(
+    post,
+    path = "/vehicles",
+    request_body = PostVehicle,
+    responses(
+        (status = 200, description = "Created vehicle id", body = i32),
+        (status = 400, description = "Validation failed", body = ErrorBody),
+        (status = 401, description = "Missing or invalid token", body = ErrorBody),
+    ),
+    security(("bearer_auth" = []))
+)]
 #[post("/vehicles")]
 async fn post_vehicle(
     data: web::Data<AppState>,
+    _auth: AuthUser,
     request: web::Json<PostVehicle>,
-) -> impl Responder {
-    let result = sqlx::query_as!(
+) -> Result<HttpResponse, AppError> {
+    request.validate()?;
+
+    let record = sqlx::query_as!(
         Record,
         r#"
         INSERT INTO public.vehicles
@@ -72,21 +156,30 @@ async fn post_vehicle(
         request.description
     )
     .fetch_one(&data.db)
-    .await;
+    .await?;
 
-    match result {
-        Ok(record) => HttpResponse::Ok().json(record.id),
-        Err(_) => HttpResponse::InternalServerError().body("Failed to create vehicle"),
-    }
+    Ok(ok(record.id))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/vehicles/{id}",
+    params(("id" = i32, Path, description = "Vehicle id")),
+    responses(
+        (status = 200, description = "Vehicle deleted"),
+        (status = 404, description = "Vehicle not found", body = ErrorBody),
+        (status = 401, description = "Missing or invalid token", body = ErrorBody),
+    ),
+    security(("bearer_auth" = []))
+)]
 #[delete("/vehicles/{id}")]
 async fn delete_vehicle_by_id(
     data: web::Data<AppState>,
+    _auth: AuthUser,
     path: web::Path<(i32,)>,
-) -> impl Responder {
+) -> Result<HttpResponse, AppError> {
     let vehicle_id = path.into_inner().0;
-    let result = sqlx::query_as!(
+    let deleted = sqlx::query_as!(
         Record,
         r#"
         DELETE FROM public.vehicles
@@ -96,22 +189,30 @@ async fn delete_vehicle_by_id(
         vehicle_id,
     )
     .fetch_optional(&data.db)
-    .await;
+    .await?;
 
-    match result {
-        Ok(Some(_)) => HttpResponse::Ok().body("Vehicle deleted"),
-        Ok(None) => HttpResponse::NotFound().body("Vehicle not found"),
-        Err(_) => HttpResponse::InternalServerError().body("Failed to delete vehicle"),
+    match deleted {
+        Some(_) => Ok(ok("Vehicle deleted")),
+        None => Err(AppError::NotFound("Vehicle not found".to_string())),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}/odometer",
+    params(("id" = i32, Path, description = "Vehicle id")),
+    responses(
+        (status = 200, description = "Latest odometer reading", body = OdometerLatestModel),
+        (status = 404, description = "No odometer record", body = ErrorBody),
+    )
+)]
 #[get("/vehicles/{id}/odometer")]
 async fn get_vehicle_odometer_by_id(
     data: web::Data<AppState>,
     path: web::Path<(i32,)>,
-) -> impl Responder {
+) -> Result<HttpResponse, AppError> {
     let vehicle_id = path.into_inner().0;
-    let result = sqlx::query_as!(
+    let odometer_latest = sqlx::query_as!(
         OdometerLatestModel,
         r#"
         SELECT o.vehicle_id, v.name AS vehicle_name, o.odometer, o.timestamp
@@ -124,25 +225,167 @@ async fn get_vehicle_odometer_by_id(
         vehicle_id,
     )
     .fetch_optional(&data.db)
-    .await;
+    .await?
+    .ok_or_else(|| AppError::NotFound("No odometer record".to_string()))?;
+
+    Ok(ok(odometer_latest))
+}
+
+const ODOMETER_HISTORY_DEFAULT_LIMIT: i64 = 50;
+const ODOMETER_HISTORY_MAX_LIMIT: i64 = 200;
+
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}/odometer/history",
+    params(
+        ("id" = i32, Path, description = "Vehicle id"),
+        ("from" = Option<NaiveDateTime>, Query, description = "Only include readings at or after this timestamp"),
+        ("to" = Option<NaiveDateTime>, Query, description = "Only include readings at or before this timestamp"),
+        ("limit" = Option<i64>, Query, description = "Max rows to return (default 50, capped at 200)"),
+        ("offset" = Option<i64>, Query, description = "Rows to skip"),
+    ),
+    responses((status = 200, description = "Odometer readings ordered by timestamp", body = [OdometerRecordModel]))
+)]
+#[get("/vehicles/{id}/odometer/history")]
+async fn get_vehicle_odometer_history(
+    data: web::Data<AppState>,
+    path: web::Path<(i32,)>,
+    query: web::Query<OdometerHistoryQuery>,
+) -> Result<HttpResponse, AppError> {
+    let vehicle_id = path.into_inner().0;
+    let limit = query
+        .limit
+        .unwrap_or(ODOMETER_HISTORY_DEFAULT_LIMIT)
+        .clamp(1, ODOMETER_HISTORY_MAX_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let readings = sqlx::query_as!(
+        OdometerRecordModel,
+        r#"
+        SELECT vehicle_id, odometer, "timestamp"
+        FROM vehicle_odometer
+        WHERE vehicle_id = $1
+          AND ($2::timestamp IS NULL OR "timestamp" >= $2)
+          AND ($3::timestamp IS NULL OR "timestamp" <= $3)
+        ORDER BY "timestamp" ASC
+        LIMIT $4 OFFSET $5
+        "#,
+        vehicle_id,
+        query.from,
+        query.to,
+        limit,
+        offset,
+    )
+    .fetch_all(&data.db)
+    .await?;
+
+    Ok(ok(serde_json::json!({
+        "rows": readings.len(),
+        "limit": limit,
+        "offset": offset,
+        "readings": readings,
+    })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}/odometer/summary",
+    params(
+        ("id" = i32, Path, description = "Vehicle id"),
+        ("from" = Option<NaiveDateTime>, Query, description = "Only include readings at or after this timestamp"),
+        ("to" = Option<NaiveDateTime>, Query, description = "Only include readings at or before this timestamp"),
+    ),
+    responses(
+        (status = 200, description = "Usage summary over the window", body = OdometerSummaryModel),
+        (status = 404, description = "No odometer record in range", body = ErrorBody),
+    )
+)]
+#[get("/vehicles/{id}/odometer/summary")]
+async fn get_vehicle_odometer_summary(
+    data: web::Data<AppState>,
+    path: web::Path<(i32,)>,
+    query: web::Query<OdometerSummaryQuery>,
+) -> Result<HttpResponse, AppError> {
+    let vehicle_id = path.into_inner().0;
 
-    match result {
-        Ok(Some(odometer_latest)) => HttpResponse::Ok().json(odometer_latest),
-        Ok(None) => HttpResponse::NotFound().body("No odometer record"),
-        Err(_) => HttpResponse::InternalServerError().body("Failed to query odometer"),
+    let readings = sqlx::query_as!(
+        OdometerRecordModel,
+        r#"
+        SELECT vehicle_id, odometer, "timestamp"
+        FROM vehicle_odometer
+        WHERE vehicle_id = $1
+          AND ($2::timestamp IS NULL OR "timestamp" >= $2)
+          AND ($3::timestamp IS NULL OR "timestamp" <= $3)
+        ORDER BY "timestamp" ASC
+        "#,
+        vehicle_id,
+        query.from,
+        query.to,
+    )
+    .fetch_all(&data.db)
+    .await?;
+
+    if readings.is_empty() {
+        return Err(AppError::NotFound(
+            "No odometer record in range".to_string(),
+        ));
     }
+
+    let first = readings.first().unwrap();
+    let last = readings.last().unwrap();
+
+    let total_distance: i64 = readings
+        .windows(2)
+        .map(|pair| (pair[1].odometer - pair[0].odometer).max(0) as i64)
+        .sum();
+
+    let span_days = (last.timestamp - first.timestamp).num_seconds() as f64 / 86400.0;
+    let average_daily_distance = if span_days > 0.0 {
+        total_distance as f64 / span_days
+    } else {
+        0.0
+    };
+
+    Ok(ok(OdometerSummaryModel {
+        vehicle_id,
+        first_reading: OdometerPoint {
+            odometer: first.odometer,
+            timestamp: first.timestamp,
+        },
+        last_reading: OdometerPoint {
+            odometer: last.odometer,
+            timestamp: last.timestamp,
+        },
+        total_distance,
+        average_daily_distance,
+    }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/vehicles/{id}/odometer",
+    params(("id" = i32, Path, description = "Vehicle id")),
+    request_body = PostOdometer,
+    responses(
+        (status = 200, description = "Odometer updated"),
+        (status = 400, description = "Validation failed or invalid vehicle id", body = ErrorBody),
+        (status = 401, description = "Missing or invalid token", body = ErrorBody),
+    ),
+    security(("bearer_auth" = []))
+)]
 #[post("/vehicles/{id}/odometer")]
 async fn post_odometer(
     data: web::Data<AppState>,
+    _auth: AuthUser,
     path: web::Path<(i32,)>,
     request: web::Json<PostOdometer>,
-) -> impl Responder {
+) -> Result<HttpResponse, AppError> {
+    request.validate()?;
+
     let vehicle_id = path.into_inner().0;
     let odometer = request.into_inner().odometer;
 
-    let result = sqlx::query!(
+    sqlx::query!(
         r#"
         INSERT INTO public.vehicle_odometer
         (vehicle_id, odometer, "timestamp")
@@ -152,37 +395,30 @@ async fn post_odometer(
         odometer
     )
     .execute(&data.db)
-    .await;
-
-    match result {
-        Ok(_) => HttpResponse::Ok().body("Odometer updated successfully"),
-        Err(e) => {
-            if e.to_string().contains("foreign key constraint") {
-                HttpResponse::BadRequest().body("Invalid vehicle ID")
-            } else {
-                HttpResponse::InternalServerError().body("Internal Server Error")
-            }
-        }
-    }
+    .await?;
+
+    Ok(ok("Odometer updated successfully"))
 }
 
 pub struct AppState {
     db: PgPool,
+    config: Config,
 }
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenv().ok();
-    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
-    let port = std::env::var("HTTP_PORT")
-        .expect("HTTP_PORT must be set")
-        .parse::<u16>()
-        .expect("HTTP_PORT must be a valid number");
-    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let config = Config::from_env().unwrap_or_else(|err| {
+        eprintln!("🔥 Invalid configuration: {err}");
+        std::process::exit(1);
+    });
+
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or(config.log_format.clone()));
+
     let pool = match PgPoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
+        .max_connections(config.max_db_connections)
+        .connect(&config.database_url)
         .await
     {
         Ok(pool) => {
@@ -197,9 +433,17 @@ async fn main() -> std::io::Result<()> {
 
     println!("ðŸš€ Server started successfully");
 
+    let http_host = config.http_host.clone();
+    let http_port = config.http_port;
+
     HttpServer::new(move || {
         App::new()
-            .app_data(web::Data::new(AppState { db: pool.clone() }))
+            .app_data(web::Data::new(AppState {
+                db: pool.clone(),
+                config: config.clone(),
+            }))
+            .app_data(web::JsonConfig::default().error_handler(errors::json_error_handler))
+            .app_data(web::QueryConfig::default().error_handler(errors::query_error_handler))
             .wrap(
                 Cors::default()
                     .allow_any_origin()
@@ -208,15 +452,23 @@ async fn main() -> std::io::Result<()> {
                     .supports_credentials(),
             )
             .wrap(Logger::default())
+            .configure(auth::configure)
+            .configure(maintenance::configure)
+            .service(
+                SwaggerUi::new("/swagger-ui/{_:.*}")
+                    .url("/api-docs/openapi.json", ApiDoc::openapi()),
+            )
             .service(index)
             .service(get_vehicles)
             .service(get_vehicle_by_id)
             .service(post_vehicle)
             .service(delete_vehicle_by_id)
             .service(get_vehicle_odometer_by_id)
+            .service(get_vehicle_odometer_history)
+            .service(get_vehicle_odometer_summary)
             .service(post_odometer)
     })
-    .bind(("127.0.0.1", port))?
+    .bind((http_host.as_str(), http_port))?
     .run()
     .await
 }
@@ -0,0 +1,196 @@
+use actix_web::{delete, get, post, web, HttpResponse};
+use validator::Validate;
+
+use crate::auth::AuthUser;
+use crate::errors::{ok, AppError};
+use crate::model::*;
+use crate::AppState;
+
+const MAINTENANCE_DEFAULT_LIMIT: i64 = 20;
+const MAINTENANCE_MAX_LIMIT: i64 = 100;
+
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}/maintenance",
+    params(
+        ("id" = i32, Path, description = "Vehicle id"),
+        ("limit" = Option<i64>, Query, description = "Max rows to return (default 20, capped at 100)"),
+        ("offset" = Option<i64>, Query, description = "Rows to skip"),
+    ),
+    responses((status = 200, description = "Paginated maintenance history", body = [MaintenanceRecordModel]))
+)]
+#[get("/vehicles/{id}/maintenance")]
+async fn get_maintenance_records(
+    data: web::Data<AppState>,
+    path: web::Path<(i32,)>,
+    query: web::Query<MaintenanceListQuery>,
+) -> Result<HttpResponse, AppError> {
+    let vehicle_id = path.into_inner().0;
+    let limit = query
+        .limit
+        .unwrap_or(MAINTENANCE_DEFAULT_LIMIT)
+        .clamp(1, MAINTENANCE_MAX_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let records = sqlx::query_as!(
+        MaintenanceRecordModel,
+        r#"
+        SELECT id, vehicle_id, service_type, cost, odometer, performed_at, notes
+        FROM maintenance_records
+        WHERE vehicle_id = $1
+        ORDER BY performed_at DESC
+        LIMIT $2 OFFSET $3
+        "#,
+        vehicle_id,
+        limit,
+        offset,
+    )
+    .fetch_all(&data.db)
+    .await?;
+
+    Ok(ok(serde_json::json!({
+        "rows": records.len(),
+        "limit": limit,
+        "offset": offset,
+        "records": records,
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/vehicles/{id}/maintenance",
+    params(("id" = i32, Path, description = "Vehicle id")),
+    request_body = PostMaintenanceRecord,
+    responses(
+        (status = 200, description = "Created maintenance record id", body = i32),
+        (status = 400, description = "Validation failed or invalid vehicle id", body = crate::errors::ErrorBody),
+        (status = 401, description = "Missing or invalid token", body = crate::errors::ErrorBody),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/vehicles/{id}/maintenance")]
+async fn post_maintenance_record(
+    data: web::Data<AppState>,
+    _auth: AuthUser,
+    path: web::Path<(i32,)>,
+    request: web::Json<PostMaintenanceRecord>,
+) -> Result<HttpResponse, AppError> {
+    request.validate()?;
+
+    let vehicle_id = path.into_inner().0;
+    let record = sqlx::query_as!(
+        Record,
+        r#"
+        INSERT INTO maintenance_records
+        (vehicle_id, service_type, cost, odometer, performed_at, notes)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id;
+        "#,
+        vehicle_id,
+        request.service_type,
+        request.cost,
+        request.odometer,
+        request.performed_at,
+        request.notes,
+    )
+    .fetch_one(&data.db)
+    .await?;
+
+    Ok(ok(record.id))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/maintenance/{id}",
+    params(("id" = i32, Path, description = "Maintenance record id")),
+    responses(
+        (status = 200, description = "Maintenance record deleted"),
+        (status = 404, description = "Maintenance record not found", body = crate::errors::ErrorBody),
+        (status = 401, description = "Missing or invalid token", body = crate::errors::ErrorBody),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/maintenance/{id}")]
+async fn delete_maintenance_record(
+    data: web::Data<AppState>,
+    _auth: AuthUser,
+    path: web::Path<(i32,)>,
+) -> Result<HttpResponse, AppError> {
+    let record_id = path.into_inner().0;
+    let deleted = sqlx::query_as!(
+        Record,
+        r#"
+        DELETE FROM maintenance_records
+        WHERE id = $1
+        RETURNING id;
+        "#,
+        record_id,
+    )
+    .fetch_optional(&data.db)
+    .await?;
+
+    match deleted {
+        Some(_) => Ok(ok("Maintenance record deleted")),
+        None => Err(AppError::NotFound(
+            "Maintenance record not found".to_string(),
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/vehicles/{id}/maintenance/due",
+    params(
+        ("id" = i32, Path, description = "Vehicle id"),
+        ("interval_km" = i32, Query, description = "Service interval in km"),
+    ),
+    responses(
+        (status = 200, description = "Whether the vehicle is due for service", body = MaintenanceDueModel),
+        (status = 404, description = "No odometer record", body = crate::errors::ErrorBody),
+    )
+)]
+#[get("/vehicles/{id}/maintenance/due")]
+async fn get_maintenance_due(
+    data: web::Data<AppState>,
+    path: web::Path<(i32,)>,
+    query: web::Query<MaintenanceDueQuery>,
+) -> Result<HttpResponse, AppError> {
+    let vehicle_id = path.into_inner().0;
+
+    let current_odometer = sqlx::query_scalar!(
+        r#"SELECT odometer FROM vehicle_odometer WHERE vehicle_id = $1 ORDER BY "timestamp" DESC LIMIT 1"#,
+        vehicle_id,
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound("No odometer record".to_string()))?;
+
+    let odometer_at_last_service = sqlx::query_scalar!(
+        r#"SELECT odometer FROM maintenance_records WHERE vehicle_id = $1 ORDER BY performed_at DESC LIMIT 1"#,
+        vehicle_id,
+    )
+    .fetch_optional(&data.db)
+    .await?;
+
+    let km_since_last_service = odometer_at_last_service.map(|last| current_odometer - last);
+    let due = match km_since_last_service {
+        Some(km_since) => km_since >= query.interval_km,
+        None => true,
+    };
+
+    Ok(ok(MaintenanceDueModel {
+        vehicle_id,
+        interval_km: query.interval_km,
+        current_odometer,
+        odometer_at_last_service,
+        km_since_last_service,
+        due,
+    }))
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_maintenance_records)
+        .service(post_maintenance_record)
+        .service(delete_maintenance_record)
+        .service(get_maintenance_due);
+}
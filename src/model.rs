@@ -1,25 +1,37 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 #[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
 pub struct Record {
     pub id: i32,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema)]
 pub struct VehicleModel {
     pub id: i32,
     pub name: String,
     pub description: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GetVehiclesQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub sort: Option<String>,
+    pub q: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct PostVehicle {
+    #[validate(length(min = 1, max = 100, message = "name must be 1-100 characters"))]
     pub name: String,
+    #[validate(length(min = 1, message = "description must not be empty"))]
     pub description: String,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema)]
 pub struct OdometerLatestModel {
     pub vehicle_id: i32,
     pub vehicle_name: String,
@@ -27,7 +39,106 @@ pub struct OdometerLatestModel {
     pub timestamp: NaiveDateTime,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct PostOdometer {
+    #[validate(range(min = 0, message = "odometer must not be negative"))]
+    pub odometer: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+pub struct UserModel {
+    pub id: i32,
+    pub username: String,
+    pub password_hash: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoginResponse {
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OdometerHistoryQuery {
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct OdometerSummaryQuery {
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct OdometerRecordModel {
+    pub vehicle_id: i32,
     pub odometer: i32,
+    pub timestamp: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OdometerPoint {
+    pub odometer: i32,
+    pub timestamp: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OdometerSummaryModel {
+    pub vehicle_id: i32,
+    pub first_reading: OdometerPoint,
+    pub last_reading: OdometerPoint,
+    pub total_distance: i64,
+    pub average_daily_distance: f64,
+}
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema)]
+pub struct MaintenanceRecordModel {
+    pub id: i32,
+    pub vehicle_id: i32,
+    pub service_type: String,
+    pub cost: f64,
+    pub odometer: i32,
+    pub performed_at: NaiveDateTime,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PostMaintenanceRecord {
+    #[validate(length(min = 1, max = 100, message = "service_type must be 1-100 characters"))]
+    pub service_type: String,
+    #[validate(range(min = 0.0, message = "cost must not be negative"))]
+    pub cost: f64,
+    #[validate(range(min = 0, message = "odometer must not be negative"))]
+    pub odometer: i32,
+    pub performed_at: NaiveDateTime,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MaintenanceListQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MaintenanceDueQuery {
+    pub interval_km: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaintenanceDueModel {
+    pub vehicle_id: i32,
+    pub interval_km: i32,
+    pub current_odometer: i32,
+    pub odometer_at_last_service: Option<i32>,
+    pub km_since_last_service: Option<i32>,
+    pub due: bool,
 }
@@ -0,0 +1,60 @@
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::errors::ErrorBody;
+use crate::model::{
+    LoginRequest, LoginResponse, MaintenanceDueModel, MaintenanceRecordModel, OdometerLatestModel,
+    OdometerPoint, OdometerRecordModel, OdometerSummaryModel, PostMaintenanceRecord, PostOdometer,
+    PostVehicle, VehicleModel,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::get_vehicles,
+        crate::get_vehicle_by_id,
+        crate::post_vehicle,
+        crate::delete_vehicle_by_id,
+        crate::get_vehicle_odometer_by_id,
+        crate::get_vehicle_odometer_history,
+        crate::get_vehicle_odometer_summary,
+        crate::post_odometer,
+        crate::auth::login,
+        crate::maintenance::get_maintenance_records,
+        crate::maintenance::post_maintenance_record,
+        crate::maintenance::delete_maintenance_record,
+        crate::maintenance::get_maintenance_due,
+    ),
+    components(schemas(
+        VehicleModel,
+        PostVehicle,
+        OdometerLatestModel,
+        OdometerRecordModel,
+        OdometerPoint,
+        OdometerSummaryModel,
+        PostOdometer,
+        LoginRequest,
+        LoginResponse,
+        MaintenanceRecordModel,
+        PostMaintenanceRecord,
+        MaintenanceDueModel,
+        ErrorBody,
+    )),
+    modifiers(&SecurityAddon),
+    tags((name = "vehicle-management-be", description = "Vehicle management API"))
+)]
+pub struct ApiDoc;
+
+/// Registers the `bearer_auth` scheme referenced by the guarded routes.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            );
+        }
+    }
+}